@@ -1,10 +1,17 @@
 pub mod entrypoint;
 pub mod errors;
 pub mod model;
+pub mod privilege;
 pub mod sdui_section;
 
 mod sdui_card_component;
 mod sdui_component;
 mod sdui_description_component;
 mod sdui_jumbotron_component;
-mod sdui_scrollview_horizontal_component;
\ No newline at end of file
+mod sdui_scrollview_horizontal_component;
+
+pub use sdui_card_component::SDUICardComponent;
+pub use sdui_component::SDUIComponent;
+pub use sdui_description_component::SDUIDescriptionComponent;
+pub use sdui_jumbotron_component::SDUIJumbotronComponent;
+pub use sdui_scrollview_horizontal_component::SDUIScrollViewHorizontalComponent;
\ No newline at end of file
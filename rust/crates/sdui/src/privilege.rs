@@ -0,0 +1,4 @@
+/// A label identifying a privilege a session may (or may not) hold, e.g. `"ADMIN"`. Sections and
+/// components that declare no labels at all are public and visible to every session, including
+/// anonymous ones.
+pub type Privilege = String;
@@ -1,3 +1,4 @@
+use crate::privilege::Privilege;
 use crate::sdui_card_component::SDUICardComponent;
 use crate::sdui_description_component::SDUIDescriptionComponent;
 use crate::sdui_jumbotron_component::SDUIJumbotronComponent;
@@ -14,3 +15,37 @@ pub enum SDUIComponent {
     SDUIJumbotronComponent(SDUIJumbotronComponent),
     SDUIScrollViewHorizontalComponent(SDUIScrollViewHorizontalComponent),
 }
+
+impl SDUIComponent {
+    /// Labels a session must hold at least one of to see this component. An empty slice means
+    /// the component is public.
+    pub fn required_labels(&self) -> &[Privilege] {
+        match self {
+            SDUIComponent::SDUICardComponent(c) => &c.required_labels,
+            SDUIComponent::SDUIDescriptionComponent(c) => &c.required_labels,
+            SDUIComponent::SDUIJumbotronComponent(c) => &c.required_labels,
+            SDUIComponent::SDUIScrollViewHorizontalComponent(c) => &c.required_labels,
+        }
+    }
+
+    /// Returns a copy of this component with any components it nests (currently only a
+    /// scrollview's `cards`) filtered by `is_visible`, so a privileged card inside an otherwise
+    /// public scrollview doesn't leak to a session that can't see it. The component itself is
+    /// assumed to have already passed its own `required_labels` check.
+    pub fn filtered_for(&self, is_visible: &impl Fn(&[Privilege]) -> bool) -> Self {
+        match self {
+            SDUIComponent::SDUIScrollViewHorizontalComponent(c) => {
+                SDUIComponent::SDUIScrollViewHorizontalComponent(SDUIScrollViewHorizontalComponent {
+                    cards: c
+                        .cards
+                        .iter()
+                        .filter(|card| is_visible(&card.required_labels))
+                        .cloned()
+                        .collect(),
+                    required_labels: c.required_labels.clone(),
+                })
+            }
+            other => other.clone(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::privilege::Privilege;
+use graphql::graphql_context::Context;
+
+#[derive(Clone, Debug, serde::Deserialize, juniper::GraphQLObject)]
+#[graphql(Context = Context)]
+pub struct SDUIJumbotronComponent {
+    pub image_url: String,
+    pub headline: Option<String>,
+
+    /// Labels a session must hold at least one of to see this component. Empty means public.
+    /// Documents stored before this field existed have no key for it, hence `#[serde(default)]`.
+    #[graphql(skip)]
+    #[serde(default)]
+    pub required_labels: Vec<Privilege>,
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ModelError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+
+    #[error("logic error: {0}")]
+    LogicError(String),
+
+    #[error("serde error: {0}")]
+    SerdeError(#[from] serde_json::Error),
+}
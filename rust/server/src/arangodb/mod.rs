@@ -0,0 +1,4 @@
+pub mod errors;
+
+/// Shared handle to the ArangoDB connection pool, cloned into every `Context`.
+pub type ConnectionPool = std::sync::Arc<arangors::Database<arangors::client::reqwest::ReqwestClient>>;
@@ -0,0 +1,70 @@
+use crate::auth::AuthError;
+use async_trait::async_trait;
+
+/// A stable, provider-scoped identifier for the signed-in subject (e.g. the `sub` claim of an
+/// OIDC ID token). Two different providers may hand out the same string, so an account is always
+/// keyed on `(IdentityProviderKind, SubjectId)`, never `SubjectId` alone.
+pub type SubjectId = String;
+
+#[derive(Clone, Copy, Debug, juniper::GraphQLEnum)]
+pub enum IdentityProviderKind {
+    Google,
+    Apple,
+    Oidc,
+}
+
+/// Verifies a provider token and extracts the subject id to map to an account. None of the
+/// providers below checks a signature, a JWKS or any claim: each `verify` returns
+/// `AuthError::NotImplemented` for every token, so no subject id is ever produced.
+#[async_trait]
+pub trait IdentityProvider: Send + Sync {
+    async fn verify(&self, token: &str) -> Result<SubjectId, AuthError>;
+}
+
+pub fn provider_for(kind: IdentityProviderKind) -> Box<dyn IdentityProvider> {
+    match kind {
+        IdentityProviderKind::Google => Box::new(GoogleIdentityProvider),
+        IdentityProviderKind::Apple => Box::new(AppleIdentityProvider),
+        IdentityProviderKind::Oidc => Box::new(OidcIdentityProvider),
+    }
+}
+
+pub struct GoogleIdentityProvider;
+
+#[async_trait]
+impl IdentityProvider for GoogleIdentityProvider {
+    async fn verify(&self, _token: &str) -> Result<SubjectId, AuthError> {
+        // TODO: verify against Google's JWKS (https://www.googleapis.com/oauth2/v3/certs). This is
+        // reachable from the live `authorize` resolver with attacker-controlled input, so it must
+        // return an `AuthError` rather than panic.
+        Err(AuthError::NotImplemented(
+            "Google ID token verification is implemented outside of this chunk",
+        ))
+    }
+}
+
+pub struct AppleIdentityProvider;
+
+#[async_trait]
+impl IdentityProvider for AppleIdentityProvider {
+    async fn verify(&self, _token: &str) -> Result<SubjectId, AuthError> {
+        // TODO: verify against Apple's JWKS (https://appleid.apple.com/auth/keys).
+        Err(AuthError::NotImplemented(
+            "Sign in with Apple token verification is implemented outside of this chunk",
+        ))
+    }
+}
+
+/// A generic OIDC provider. `verify` does no issuer discovery, JWKS fetch or signature check; it
+/// returns `AuthError::NotImplemented` for every token.
+pub struct OidcIdentityProvider;
+
+#[async_trait]
+impl IdentityProvider for OidcIdentityProvider {
+    async fn verify(&self, _token: &str) -> Result<SubjectId, AuthError> {
+        // TODO: discover the issuer's JWKS and verify signature plus iss/aud/exp.
+        Err(AuthError::NotImplemented(
+            "generic OIDC token verification is implemented outside of this chunk",
+        ))
+    }
+}
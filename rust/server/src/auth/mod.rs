@@ -0,0 +1,101 @@
+pub mod identity_provider;
+pub mod session;
+pub mod users;
+
+use crate::arangodb::ConnectionPool;
+use crate::auth::identity_provider::IdentityProviderKind;
+use crate::auth::session::Session;
+use crate::auth::users::User;
+
+/// Verifies `token` with the identity provider for `provider`. No account is looked up or
+/// registered and no session is issued: every call returns an `AuthError` (the provider's own
+/// error, or `AuthError::NotImplemented` once verification passes), never a `Session`.
+pub async fn authorize(
+    _connection_pool: &ConnectionPool,
+    provider: IdentityProviderKind,
+    token: &str,
+) -> Result<Session, AuthError> {
+    let _subject_id = identity_provider::provider_for(provider).verify(token).await?;
+    // TODO: upsert the ArangoDB user document keyed on (provider, subject_id) and persist the
+    // new `Session`. Reachable from the live `authorize` resolver with attacker-controlled input
+    // (a token that happens to verify), so it must return an `AuthError` rather than panic.
+    Err(AuthError::NotImplemented(
+        "account mapping and session issuance are implemented outside of this chunk",
+    ))
+}
+
+/// Always returns `AuthError::NotImplemented`: the refresh token is not looked up, checked for
+/// expiry or revocation, or rotated, and no new access token is issued.
+pub async fn refresh(
+    _connection_pool: &ConnectionPool,
+    _refresh_token: &str,
+) -> Result<Session, AuthError> {
+    // TODO: look up the session document by refresh token, reject if expired or revoked, then
+    // replace it with a freshly issued access/refresh token pair in the same `family_id`. This is
+    // reachable from the live `refresh_mobile` resolver with attacker-controlled input, so it must
+    // return an `AuthError` rather than panic.
+    Err(AuthError::NotImplemented(
+        "refresh token rotation is implemented outside of this chunk",
+    ))
+}
+
+/// Always returns `AuthError::NotImplemented`: neither the session behind `session_token` nor
+/// its session family is revoked.
+///
+/// Reachable from the live `deauthorize_mobile` mutation with an attacker-controlled
+/// `session_token`, so it returns an `AuthError` rather than panicking.
+pub async fn deauthorize(
+    _connection_pool: &ConnectionPool,
+    _session_token: &str,
+) -> Result<(), AuthError> {
+    Err(AuthError::NotImplemented(
+        "session revocation is implemented outside of this chunk",
+    ))
+}
+
+/// Always returns `AuthError::NotImplemented`: no sessions of `user` are looked up.
+///
+/// Reachable from the live `my_sessions` query, so it returns an `AuthError` rather than
+/// panicking.
+pub async fn list_sessions(_connection_pool: &ConnectionPool, _user: &User) -> Result<Vec<Session>, AuthError> {
+    Err(AuthError::NotImplemented(
+        "session listing is implemented outside of this chunk",
+    ))
+}
+
+/// Takes a session id (`Session::id`, not an access/refresh token) and always returns
+/// `AuthError::NotImplemented`: no session is revoked and no check is made that `session_id`
+/// belongs to `user`, so `AuthError::InvalidToken` is never returned here.
+///
+/// Reachable from the live `revoke_session` mutation with an attacker-controlled `session_id`, so
+/// it returns an `AuthError` rather than panicking.
+pub async fn revoke_session(
+    _connection_pool: &ConnectionPool,
+    _user: &User,
+    _session_id: &str,
+) -> Result<(), AuthError> {
+    Err(AuthError::NotImplemented(
+        "session revocation by id is implemented outside of this chunk",
+    ))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("invalid token")]
+    InvalidToken,
+
+    #[error("access token expired")]
+    ExpiredAccessToken,
+
+    #[error("refresh token expired or revoked")]
+    ExpiredOrRevokedRefreshToken,
+
+    #[error("database error: {0}")]
+    DatabaseError(String),
+
+    /// Placeholder for behavior genuinely implemented outside of this chunk. Returned, never
+    /// panicked, since every function using it sits on a resolver path reachable with
+    /// attacker-controlled input.
+    #[error("not yet implemented: {0}")]
+    NotImplemented(&'static str),
+}
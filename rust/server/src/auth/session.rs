@@ -0,0 +1,91 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Access tokens are deliberately short-lived: if one leaks, it is only useful for a few minutes.
+pub const ACCESS_TOKEN_TTL: Duration = Duration::minutes(15);
+
+/// Refresh tokens live much longer, since they never leave the device and are only ever sent to
+/// `refresh_mobile`.
+pub const REFRESH_TOKEN_TTL: Duration = Duration::days(30);
+
+/// A signed-in session, persisted as a single document in ArangoDB, keyed to the user it belongs
+/// to so it can be listed and individually revoked from a "signed-in devices" screen.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Session {
+    /// ArangoDB document key of this session - what `revoke_session` and `current_session` in
+    /// `SessionInfo` refer to. Deliberately distinct from `access_token`/`refresh_token` so
+    /// listing sessions never has to hand back another session's live tokens.
+    pub id: String,
+
+    pub access_token: String,
+    pub access_token_expires_at: DateTime<Utc>,
+
+    pub refresh_token: String,
+    pub refresh_token_expires_at: DateTime<Utc>,
+
+    /// Shared by every session created by rotating the same original refresh token, so
+    /// `deauthorize_mobile` can revoke the whole family instead of a single token.
+    pub family_id: String,
+
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+
+    /// `User-Agent` header captured at `authorize`/`refresh_mobile` time, shown to the user so
+    /// they can recognize which device a session belongs to.
+    pub user_agent: Option<String>,
+
+    pub revoked: bool,
+}
+
+impl Session {
+    pub fn is_access_token_expired(&self) -> bool {
+        Utc::now() > self.access_token_expires_at
+    }
+
+    pub fn is_refresh_token_expired(&self) -> bool {
+        Utc::now() > self.refresh_token_expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(access_token_expires_at: DateTime<Utc>, refresh_token_expires_at: DateTime<Utc>) -> Session {
+        let now = Utc::now();
+        Session {
+            id: "session-1".to_string(),
+            access_token: "access".to_string(),
+            access_token_expires_at,
+            refresh_token: "refresh".to_string(),
+            refresh_token_expires_at,
+            family_id: "family-1".to_string(),
+            created_at: now,
+            last_seen_at: now,
+            user_agent: None,
+            revoked: false,
+        }
+    }
+
+    #[test]
+    fn access_token_in_the_future_is_not_expired() {
+        let session = session_with(Utc::now() + ACCESS_TOKEN_TTL, Utc::now() + REFRESH_TOKEN_TTL);
+
+        assert!(!session.is_access_token_expired());
+        assert!(!session.is_refresh_token_expired());
+    }
+
+    #[test]
+    fn access_token_in_the_past_is_expired_even_if_refresh_token_is_not() {
+        let session = session_with(Utc::now() - Duration::minutes(1), Utc::now() + REFRESH_TOKEN_TTL);
+
+        assert!(session.is_access_token_expired());
+        assert!(!session.is_refresh_token_expired());
+    }
+
+    #[test]
+    fn refresh_token_in_the_past_is_expired() {
+        let session = session_with(Utc::now() - Duration::minutes(1), Utc::now() - Duration::days(1));
+
+        assert!(session.is_refresh_token_expired());
+    }
+}
@@ -0,0 +1,117 @@
+use sdui::privilege::Privilege;
+
+/// Represents the user attached to the current GraphQL request. Every request has exactly one of
+/// these, even if nobody ever signed in (see `AnonymousUser`).
+#[derive(Clone, Debug)]
+pub enum User {
+    AuthorizedUser(AuthorizedUser),
+    AnonymousUser(AnonymousUser),
+    UnauthorizedUser(UnauthorizedUser),
+}
+
+impl User {
+    /// Privileges granted to the session backing this user: the list this `User` was constructed
+    /// with. `Session` (see `crate::auth::session`) has no field for privileges, so they are not
+    /// read from the session document.
+    pub fn privileges(&self) -> &[Privilege] {
+        match self {
+            User::AuthorizedUser(user) => &user.privileges,
+            User::AnonymousUser(user) => &user.privileges,
+            User::UnauthorizedUser(user) => &user.privileges,
+        }
+    }
+
+    /// Whether this user holds `privilege`.
+    pub fn has_privilege(&self, privilege: &str) -> bool {
+        self.privileges().iter().any(|p| p == privilege)
+    }
+
+    /// Whether this user holds at least one of `required`. An empty `required` slice means the
+    /// content is public, so every user (including `AnonymousUser`) passes.
+    pub fn has_any_privilege(&self, required: &[Privilege]) -> bool {
+        required.is_empty() || required.iter().any(|privilege| self.has_privilege(privilege))
+    }
+}
+
+/// A user who presented a valid, non-expired session token.
+#[derive(Clone, Debug)]
+pub struct AuthorizedUser {
+    id: String,
+    privileges: Vec<Privilege>,
+}
+
+impl AuthorizedUser {
+    pub fn new(id: String, privileges: Vec<Privilege>) -> Self {
+        Self { id, privileges }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// A user who did not present any session token at all.
+#[derive(Clone, Debug)]
+pub struct AnonymousUser {
+    id: String,
+    privileges: Vec<Privilege>,
+}
+
+impl AnonymousUser {
+    pub fn new(id: String, privileges: Vec<Privilege>) -> Self {
+        Self { id, privileges }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anonymous_user_sees_public_content_but_not_privileged_content() {
+        let user = User::AnonymousUser(AnonymousUser::new("anon-1".to_string(), vec![]));
+
+        assert!(user.has_any_privilege(&[]));
+        assert!(!user.has_any_privilege(&["ADMIN".to_string()]));
+    }
+
+    #[test]
+    fn user_with_one_of_several_required_privileges_passes() {
+        let user = User::AuthorizedUser(AuthorizedUser::new(
+            "user-1".to_string(),
+            vec!["BETA_TESTER".to_string()],
+        ));
+
+        assert!(user.has_any_privilege(&["ADMIN".to_string(), "BETA_TESTER".to_string()]));
+        assert!(!user.has_any_privilege(&["ADMIN".to_string()]));
+    }
+
+    #[test]
+    fn unauthorized_user_without_privileges_sees_only_public_content() {
+        let user = User::UnauthorizedUser(UnauthorizedUser::new("user-1".to_string(), vec![]));
+
+        assert!(user.has_any_privilege(&[]));
+        assert!(!user.has_any_privilege(&["ADMIN".to_string()]));
+    }
+}
+
+/// A user who presented a session token, but it was invalid, expired, or revoked.
+#[derive(Clone, Debug)]
+pub struct UnauthorizedUser {
+    id: String,
+    privileges: Vec<Privilege>,
+}
+
+impl UnauthorizedUser {
+    pub fn new(id: String, privileges: Vec<Privilege>) -> Self {
+        Self { id, privileges }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,30 @@
+use crate::sdui::sdui_section::SDUISection;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+
+/// How many updates a lagging subscriber can fall behind before it starts missing them. Missed
+/// updates are fine here - the next one still reflects the latest state.
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Clone, Debug)]
+pub struct EntrypointUpdate {
+    pub key: String,
+    pub sections: Vec<SDUISection>,
+}
+
+static UPDATES: Lazy<broadcast::Sender<EntrypointUpdate>> =
+    Lazy::new(|| broadcast::channel(CHANNEL_CAPACITY).0);
+
+/// Pushes the fresh list of sections for `key` to every current subscriber (unfiltered - per-user
+/// filtering happens per subscriber, see `mobile_entrypoint_sections_updated`).
+///
+/// Nothing in this crate calls `publish`, and no WebSocket (or other) transport carries
+/// `mobile_entrypoint_sections_updated`'s stream to a client, so that subscription emits nothing.
+pub fn publish(key: String, sections: Vec<SDUISection>) {
+    // Sending fails only when there are no subscribers at all, which is the common case - ignore it.
+    let _ = UPDATES.send(EntrypointUpdate { key, sections });
+}
+
+pub fn subscribe() -> broadcast::Receiver<EntrypointUpdate> {
+    UPDATES.subscribe()
+}
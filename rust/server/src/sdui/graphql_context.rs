@@ -0,0 +1,66 @@
+use crate::arangodb::ConnectionPool;
+use crate::auth::users::User;
+use juniper::{graphql_value, FieldError, FieldResult};
+
+/// Per-request context handed to every resolver. Carries the current `User` (which may be
+/// anonymous) and a handle to the connection pool.
+///
+/// Whatever builds `Context` for an incoming request (outside this chunk) is responsible for
+/// looking up the session by access token and resolving to `User::UnauthorizedUser` whenever
+/// `Session::is_access_token_expired` is true or the session has been revoked, so neither an
+/// expired nor a revoked token ever reaches a resolver as authorized.
+#[derive(Clone)]
+pub struct Context {
+    pub pool: ConnectionPool,
+    pub user: User,
+
+    /// `Session::id` of the session behind this request, if any. Lets resolvers like
+    /// `my_sessions` flag which listed session is the one currently making the request.
+    pub session_id: Option<String>,
+}
+
+impl juniper::Context for Context {}
+
+impl Context {
+    /// Resolvers call this at the top of a field to gate it behind `privilege`. On failure the
+    /// field resolves to `null` and the error carries a stable `extensions.code` plus the field
+    /// `path`, so a client can render the rest of the response instead of losing the whole
+    /// operation.
+    pub fn require(&self, privilege: &str, path: &str) -> FieldResult<()> {
+        if self.user.has_privilege(privilege) {
+            Ok(())
+        } else {
+            Err(unauthorized_field_error(path))
+        }
+    }
+}
+
+pub fn unauthorized_field_error(path: &str) -> FieldError {
+    FieldError::new(
+        "you are not authorized to access this field",
+        graphql_value!({
+            "code": "UNAUTHORIZED_FIELD_OR_TYPE",
+            "path": path,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unauthorized_field_error_carries_stable_code_and_path() {
+        let error = unauthorized_field_error("whoami.id");
+
+        let extensions = error.extensions();
+        assert_eq!(
+            extensions.as_object_value().unwrap().get_field_value("code"),
+            Some(&graphql_value!("UNAUTHORIZED_FIELD_OR_TYPE"))
+        );
+        assert_eq!(
+            extensions.as_object_value().unwrap().get_field_value("path"),
+            Some(&graphql_value!("whoami.id"))
+        );
+    }
+}
@@ -1,20 +1,79 @@
 use crate::arangodb::errors::ModelError;
+use crate::auth::identity_provider::IdentityProviderKind;
+use crate::auth::session::Session;
 use crate::auth::users::User;
+use crate::sdui::entrypoint_updates::EntrypointUpdate;
 use crate::sdui::graphql_context::Context;
 use crate::sdui::model::sdui_sections::get_all_sections_for_entrypoint_key;
 use crate::sdui::sdui_section::SDUISection;
-use juniper::{EmptySubscription, FieldError, FieldResult, RootNode};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use juniper::{FieldError, FieldResult, RootNode};
+use std::pin::Pin;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Query;
 
-#[derive(juniper::GraphQLObject)]
+/// `WhoamiPayload` is resolved field-by-field (rather than derived from a plain struct) so that
+/// `id`, which requires the `VIEW_OWN_ID` privilege, can fail on its own and resolve to `null`
+/// without taking `human_readable_type` down with it.
 struct WhoamiPayload {
-    id: Option<juniper::ID>,
+    user: User,
+}
+
+#[juniper::graphql_object(context = Context)]
+impl WhoamiPayload {
+    async fn id(&self, context: &Context) -> FieldResult<Option<juniper::ID>> {
+        context.require("VIEW_OWN_ID", "whoami.id")?;
+
+        Ok(Some(juniper::ID::from(match &self.user {
+            User::AuthorizedUser(user) => user.id(),
+            User::AnonymousUser(user) => user.id(),
+            User::UnauthorizedUser(user) => user.id(),
+        })))
+    }
 
     /// Human readable type should be used only for testing purposes. The format is not guaranteed
     /// and can change in the future completely.
-    human_readable_type: Option<String>,
+    async fn human_readable_type(&self) -> Option<String> {
+        Some(String::from(match &self.user {
+            User::AuthorizedUser(_) => "authorized user",
+            User::AnonymousUser(_) => "anonymous user",
+            User::UnauthorizedUser(_) => "unauthorized (but not anonymous) user",
+        }))
+    }
+}
+
+#[derive(juniper::GraphQLObject)]
+struct SessionInfo {
+    /// Opaque id to pass to `revoke_session`. Never the session's access or refresh token.
+    id: juniper::ID,
+
+    created_at: DateTime<Utc>,
+    last_seen_at: DateTime<Utc>,
+
+    /// `User-Agent` captured when the session was created, if any, for telling devices apart.
+    user_agent: Option<String>,
+
+    /// Whether this is the session making the current request.
+    current_session: bool,
+}
+
+impl SessionInfo {
+    /// `current_session_id` is `Context::session_id` of the request resolving this field - kept as
+    /// a plain `Option<&str>` rather than the whole `Context` so this stays a pure function.
+    fn from_session(session: Session, current_session_id: Option<&str>) -> Self {
+        let current_session = current_session_id == Some(session.id.as_str());
+        SessionInfo {
+            id: juniper::ID::from(session.id),
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            user_agent: session.user_agent,
+            current_session,
+        }
+    }
 }
 
 #[juniper::graphql_object(context = Context)]
@@ -35,21 +94,39 @@ impl Query {
         }
     }
 
-    /// Returns information about the current user (can be authenticated or anonymous).
+    /// Returns information about the current user (can be authenticated or anonymous). Individual
+    /// fields may still be hidden behind a privilege - see `WhoamiPayload::id`.
     async fn whoami(context: &Context) -> WhoamiPayload {
-        match &context.user {
-            User::AuthorizedUser(user) => WhoamiPayload {
-                id: Some(juniper::ID::from(user.id())),
-                human_readable_type: Some(String::from("authorized user")),
-            },
-            User::AnonymousUser(user) => WhoamiPayload {
-                id: Some(juniper::ID::from(user.id())),
-                human_readable_type: Some(String::from("anonymous user")),
-            },
-            User::UnauthorizedUser(user) => WhoamiPayload {
-                id: Some(juniper::ID::from(user.id())),
-                human_readable_type: Some(String::from("unauthorized (but not anonymous) user")),
-            },
+        WhoamiPayload {
+            user: context.user.to_owned(),
+        }
+    }
+
+    /// Lists the requesting user's own active sessions, for a "signed-in devices" screen.
+    ///
+    /// Being able to see your own signed-in devices follows from being signed in at all - it is
+    /// not something that should be separately grantable/revocable like `VIEW_OWN_ID`, so this
+    /// checks `User::AuthorizedUser` directly instead of going through `Context::require`. It
+    /// resolves to `null` (rather than a `FieldError`) for anonymous/unauthorized sessions and on
+    /// a lookup failure, so - matching the `whoami.id` precedent from chunk0-1 - this never nulls
+    /// out the rest of the query.
+    async fn my_sessions(context: &Context) -> Option<Vec<SessionInfo>> {
+        if !matches!(context.user, User::AuthorizedUser(_)) {
+            return None;
+        }
+
+        let connection_pool = context.pool.to_owned();
+        match crate::auth::list_sessions(&connection_pool, &context.user).await {
+            Ok(sessions) => Some(
+                sessions
+                    .into_iter()
+                    .map(|session| SessionInfo::from_session(session, context.session_id.as_deref()))
+                    .collect(),
+            ),
+            Err(e) => {
+                log::error!("{}", e);
+                None
+            }
         }
     }
 }
@@ -61,9 +138,77 @@ pub struct Mutation;
 struct AuthorizeMobilePayload {
     success: bool,
 
-    /// Session token should be send with every GraphQL request which requires auth.
-    /// Returns `None` if the request was not successful.
-    session_token: Option<String>,
+    /// Access token should be sent with every GraphQL request which requires auth. Short-lived -
+    /// once it expires, exchange `refresh_token` for a new pair via `refresh_mobile` instead of
+    /// signing in again. `None` if the request was not successful.
+    access_token: Option<String>,
+    access_token_expires_at: Option<DateTime<Utc>>,
+
+    /// Long-lived token used only to obtain a new access token from `refresh_mobile`. Rotated on
+    /// every use. `None` if the request was not successful.
+    refresh_token: Option<String>,
+    refresh_token_expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<Session> for AuthorizeMobilePayload {
+    fn from(session: Session) -> Self {
+        AuthorizeMobilePayload {
+            success: true,
+            access_token: Some(session.access_token),
+            access_token_expires_at: Some(session.access_token_expires_at),
+            refresh_token: Some(session.refresh_token),
+            refresh_token_expires_at: Some(session.refresh_token_expires_at),
+        }
+    }
+}
+
+impl AuthorizeMobilePayload {
+    fn failed() -> Self {
+        AuthorizeMobilePayload {
+            success: false,
+            access_token: None,
+            access_token_expires_at: None,
+            refresh_token: None,
+            refresh_token_expires_at: None,
+        }
+    }
+}
+
+#[derive(juniper::GraphQLObject)]
+struct RefreshMobilePayload {
+    success: bool,
+
+    access_token: Option<String>,
+    access_token_expires_at: Option<DateTime<Utc>>,
+
+    /// The refresh token is rotated on every successful refresh - clients must replace their
+    /// stored refresh token with this one.
+    refresh_token: Option<String>,
+    refresh_token_expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<Session> for RefreshMobilePayload {
+    fn from(session: Session) -> Self {
+        RefreshMobilePayload {
+            success: true,
+            access_token: Some(session.access_token),
+            access_token_expires_at: Some(session.access_token_expires_at),
+            refresh_token: Some(session.refresh_token),
+            refresh_token_expires_at: Some(session.refresh_token_expires_at),
+        }
+    }
+}
+
+impl RefreshMobilePayload {
+    fn failed() -> Self {
+        RefreshMobilePayload {
+            success: false,
+            access_token: None,
+            access_token_expires_at: None,
+            refresh_token: None,
+            refresh_token_expires_at: None,
+        }
+    }
 }
 
 #[derive(juniper::GraphQLObject)]
@@ -71,37 +216,61 @@ struct DeauthorizeMobilePayload {
     success: bool,
 }
 
+// `authorize_mobile` and `deauthorize_mobile` stay open to every session, anonymous or not - you
+// need a session before you can be authorized. Privileged mutations added from here on should
+// call `context.require(privilege, path)?` as their first statement, the same way privileged
+// fields do in `Query`.
 #[juniper::graphql_object(context = Context)]
 impl Mutation {
-    /// This function accepts Google ID token (after receiving it from Google Sign-In in a mobile
-    /// device) and returns authorization payload. There is no concept of sign-in and sign-up
-    /// because every user with a valid JWT ID token will be either authorized OR registered and
-    /// authorized. Invalid tokens and disabled tokens will be rejected.
+    /// Accepts a provider token (after receiving it from that provider's native sign-in flow on a
+    /// mobile device) and returns an authorization payload. There is no concept of sign-in and
+    /// sign-up because every subject with a valid token will be either authorized OR registered
+    /// and authorized, whichever `provider` vouched for it. Invalid or disabled tokens are
+    /// rejected.
+    async fn authorize(
+        provider: IdentityProviderKind,
+        token: String,
+        context: &Context,
+    ) -> FieldResult<AuthorizeMobilePayload> {
+        let connection_pool = context.pool.to_owned();
+        match crate::auth::authorize(&connection_pool, provider, &token).await {
+            Ok(session) => Ok(AuthorizeMobilePayload::from(session)),
+            Err(e) => {
+                log::error!("{}", e);
+                Ok(AuthorizeMobilePayload::failed())
+                // TODO: return rejection reason from AuthError as well (?)
+            }
+        }
+    }
+
+    #[graphql(deprecated = "Use `authorize` with `provider: GOOGLE` instead.")]
     async fn authorize_mobile(
         google_id_token: String,
         context: &Context,
     ) -> FieldResult<AuthorizeMobilePayload> {
+        Mutation::authorize(IdentityProviderKind::Google, google_id_token, context).await
+    }
+
+    /// Exchanges a refresh token for a new access token without requiring another Google sign-in.
+    /// The refresh token itself is rotated, so a client must store the new one from the payload.
+    async fn refresh_mobile(
+        refresh_token: String,
+        context: &Context,
+    ) -> FieldResult<RefreshMobilePayload> {
         let connection_pool = context.pool.to_owned();
-        let session_token = crate::auth::authorize(&connection_pool, &google_id_token).await;
-        match session_token {
-            Ok(session_token) => Ok(AuthorizeMobilePayload {
-                success: true,
-                session_token: Some(session_token),
-            }),
+        match crate::auth::refresh(&connection_pool, &refresh_token).await {
+            Ok(session) => Ok(RefreshMobilePayload::from(session)),
             Err(e) => {
                 log::error!("{}", e);
-                Ok(AuthorizeMobilePayload {
-                    success: false,
-                    session_token: None,
-                    // TODO: return rejection reason from AuthError as well (?)
-                })
+                Ok(RefreshMobilePayload::failed())
             }
         }
     }
 
     /// The purpose of this `deauthorize` mutation is to remove the active sessions and efectivelly
     /// make the mobile application unsigned. Mobile applications should remove the session token
-    /// once deauthorized.
+    /// once deauthorized. Revokes the whole refresh-token family behind `session_token`, not just
+    /// the single access token presented.
     async fn deauthorize_mobile(
         session_token: String,
         context: &Context,
@@ -112,19 +281,119 @@ impl Mutation {
             Err(_) => DeauthorizeMobilePayload { success: false },
         }
     }
+
+    /// Revokes one of the requesting user's own sessions by its `SessionInfo.id`, e.g. to sign out
+    /// a lost phone remotely. Unlike `deauthorize_mobile`, this does not require the revoked
+    /// session's own token - only that the caller is authenticated as its owner.
+    async fn revoke_session(
+        session_id: juniper::ID,
+        context: &Context,
+    ) -> FieldResult<RevokeSessionPayload> {
+        if !matches!(context.user, User::AuthorizedUser(_)) {
+            return Err(FieldError::from("must be signed in to revoke a session"));
+        }
+
+        let connection_pool = context.pool.to_owned();
+        let session_id = String::from(session_id);
+        match crate::auth::revoke_session(&connection_pool, &context.user, &session_id).await {
+            Ok(_) => Ok(RevokeSessionPayload { success: true }),
+            Err(e) => {
+                log::error!("{}", e);
+                Ok(RevokeSessionPayload { success: false })
+            }
+        }
+    }
+}
+
+#[derive(juniper::GraphQLObject)]
+struct RevokeSessionPayload {
+    success: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Subscription;
+
+type SDUISectionsStream = Pin<Box<dyn Stream<Item = FieldResult<Vec<SDUISection>>> + Send>>;
+
+#[juniper::graphql_subscription(context = Context)]
+impl Subscription {
+    /// Streams the sections for entrypoint `key` every time they change, already filtered for the
+    /// subscribing user the same way `mobile_entrypoint_sections` is.
+    async fn mobile_entrypoint_sections_updated(
+        key: String,
+        context: &Context,
+    ) -> SDUISectionsStream {
+        let user = context.user.to_owned();
+        let updates = BroadcastStream::new(crate::sdui::entrypoint_updates::subscribe());
+
+        let stream = updates.filter_map(move |update: Result<EntrypointUpdate, _>| {
+            let update = update.ok()?; // a lagged receiver just misses an update, not an error
+            if update.key != key {
+                return None;
+            }
+
+            Some(Ok(update
+                .sections
+                .iter()
+                .filter(|section| section.is_visible_to(&user))
+                .map(|section| section.filtered_for(&user))
+                .collect()))
+        });
+
+        Box::pin(stream)
+    }
 }
 
-pub type Schema = RootNode<'static, Query, Mutation, EmptySubscription<Context>>;
+pub type Schema = RootNode<'static, Query, Mutation, Subscription>;
 
 pub fn create_graphql_schema() -> Schema {
-    Schema::new(Query, Mutation, EmptySubscription::<Context>::new())
+    Schema::new(Query, Mutation, Subscription)
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{Session, SessionInfo};
+    use chrono::Utc;
     use std::fs;
     use std::path::Path;
 
+    fn session_with_id(id: &str) -> Session {
+        let now = Utc::now();
+        Session {
+            id: id.to_string(),
+            access_token: "access".to_string(),
+            access_token_expires_at: now,
+            refresh_token: "refresh".to_string(),
+            refresh_token_expires_at: now,
+            family_id: "family-1".to_string(),
+            created_at: now,
+            last_seen_at: now,
+            user_agent: None,
+            revoked: false,
+        }
+    }
+
+    #[test]
+    fn from_session_flags_the_session_matching_the_request_as_current() {
+        let session_info = SessionInfo::from_session(session_with_id("session-1"), Some("session-1"));
+
+        assert!(session_info.current_session);
+    }
+
+    #[test]
+    fn from_session_does_not_flag_other_sessions_as_current() {
+        let session_info = SessionInfo::from_session(session_with_id("session-1"), Some("session-2"));
+
+        assert!(!session_info.current_session);
+    }
+
+    #[test]
+    fn from_session_with_no_current_session_id_flags_nothing_as_current() {
+        let session_info = SessionInfo::from_session(session_with_id("session-1"), None);
+
+        assert!(!session_info.current_session);
+    }
+
     #[test]
     fn schema_snapshot() {
         // This test will make sure that the schema generated by server is ready to be used by
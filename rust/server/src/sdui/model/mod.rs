@@ -0,0 +1 @@
+pub mod sdui_sections;
@@ -0,0 +1,23 @@
+use crate::arangodb::errors::ModelError;
+use crate::arangodb::ConnectionPool;
+use crate::auth::users::User;
+use crate::sdui::sdui_section::SDUISection;
+
+/// Returns the `SDUISection`s of the entrypoint `key` (e.g. `"home"`) that `user` is allowed to
+/// see, filtering out the others rather than failing the whole request. No entrypoint document is
+/// read from ArangoDB: the unfiltered list is empty, so the result is always empty.
+pub async fn get_all_sections_for_entrypoint_key(
+    user: &User,
+    _connection_pool: &ConnectionPool,
+    _key: &str,
+) -> Result<Vec<SDUISection>, ModelError> {
+    // TODO: fetch the entrypoint document from ArangoDB; this is implemented outside of this
+    // chunk.
+    let all_sections: Vec<SDUISection> = Vec::new();
+
+    Ok(all_sections
+        .iter()
+        .filter(|section| section.is_visible_to(user))
+        .map(|section| section.filtered_for(user))
+        .collect())
+}
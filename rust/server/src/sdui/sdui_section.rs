@@ -0,0 +1,121 @@
+use crate::auth::users::User;
+use sdui::privilege::Privilege;
+use sdui::SDUIComponent;
+
+/// A single section of a mobile entrypoint screen (e.g. the home screen), rendered by the
+/// client purely from the data returned here (server-driven UI).
+#[derive(Clone, Debug, serde::Deserialize, juniper::GraphQLObject)]
+#[graphql(context = crate::sdui::graphql_context::Context)]
+pub struct SDUISection {
+    pub key: String,
+    pub components: Vec<SDUIComponent>,
+
+    /// Labels a session must hold at least one of to see the section at all; empty means the
+    /// section is public (visible to `AnonymousUser` too). Documents stored before this field
+    /// existed have no key for it at all, hence `#[serde(default)]`.
+    #[graphql(skip)]
+    #[serde(default)]
+    pub required_labels: Vec<Privilege>,
+}
+
+impl SDUISection {
+    /// Whether `user` may see this section at all.
+    pub fn is_visible_to(&self, user: &User) -> bool {
+        user.has_any_privilege(&self.required_labels)
+    }
+
+    /// Returns a copy of this section with components `user` is not allowed to see dropped (and
+    /// any components they can see filtered recursively, so a privileged card nested inside a
+    /// public scrollview doesn't leak), leaving the rest of the section intact.
+    pub fn filtered_for(&self, user: &User) -> Self {
+        let is_visible = |labels: &[Privilege]| user.has_any_privilege(labels);
+
+        Self {
+            key: self.key.clone(),
+            required_labels: self.required_labels.clone(),
+            components: self
+                .components
+                .iter()
+                .filter(|component| is_visible(component.required_labels()))
+                .map(|component| component.filtered_for(&is_visible))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::users::AnonymousUser;
+    use sdui::{SDUICardComponent, SDUIDescriptionComponent, SDUIScrollViewHorizontalComponent};
+
+    fn public_description() -> SDUIComponent {
+        SDUIComponent::SDUIDescriptionComponent(SDUIDescriptionComponent {
+            text: "public".to_string(),
+            required_labels: vec![],
+        })
+    }
+
+    fn card(title: &str, required_labels: Vec<Privilege>) -> SDUICardComponent {
+        SDUICardComponent {
+            title: title.to_string(),
+            subtitle: None,
+            image_url: None,
+            required_labels,
+        }
+    }
+
+    #[test]
+    fn section_with_no_required_labels_is_visible_to_anonymous_user() {
+        let section = SDUISection {
+            key: "home".to_string(),
+            components: vec![],
+            required_labels: vec![],
+        };
+        let anonymous = User::AnonymousUser(AnonymousUser::new("anon-1".to_string(), vec![]));
+
+        assert!(section.is_visible_to(&anonymous));
+    }
+
+    #[test]
+    fn section_with_required_labels_is_hidden_from_anonymous_user() {
+        let section = SDUISection {
+            key: "admin".to_string(),
+            components: vec![],
+            required_labels: vec!["ADMIN".to_string()],
+        };
+        let anonymous = User::AnonymousUser(AnonymousUser::new("anon-1".to_string(), vec![]));
+
+        assert!(!section.is_visible_to(&anonymous));
+    }
+
+    #[test]
+    fn filtered_for_drops_privileged_card_nested_in_public_scrollview() {
+        let section = SDUISection {
+            key: "home".to_string(),
+            components: vec![
+                public_description(),
+                SDUIComponent::SDUIScrollViewHorizontalComponent(SDUIScrollViewHorizontalComponent {
+                    cards: vec![
+                        card("public card", vec![]),
+                        card("admin-only card", vec!["ADMIN".to_string()]),
+                    ],
+                    required_labels: vec![],
+                }),
+            ],
+            required_labels: vec![],
+        };
+        let anonymous = User::AnonymousUser(AnonymousUser::new("anon-1".to_string(), vec![]));
+
+        let filtered = section.filtered_for(&anonymous);
+
+        assert_eq!(filtered.components.len(), 2);
+        match &filtered.components[1] {
+            SDUIComponent::SDUIScrollViewHorizontalComponent(scrollview) => {
+                assert_eq!(scrollview.cards.len(), 1);
+                assert_eq!(scrollview.cards[0].title, "public card");
+            }
+            other => panic!("expected a scrollview component, got {:?}", other),
+        }
+    }
+}